@@ -4,8 +4,10 @@ use darling::{
     util::{Flag, Override},
 };
 use proc_macro2::Span;
+use quote::ToTokens;
 use syn::{
-    Attribute, ExprPath, GenericArgument, Ident, LitStr, PathArguments, Type, spanned::Spanned,
+    Attribute, Expr, ExprPath, GenericArgument, Ident, LitStr, Meta, PathArguments, Type,
+    parse_quote, spanned::Spanned,
 };
 
 #[derive(Debug)]
@@ -22,12 +24,33 @@ pub enum EnvAttribute {
     /// #[env(from = "...")]
     Flat {
         name: LitStr,
-        from: Option<LitStr>,
+        /// `false` when `name` was derived from the field ident and is still
+        /// eligible for a container-level `#[env(rename_all = "...")]` rule.
+        name_is_explicit: bool,
+        /// Ordered fallback list of env var names to probe, tried in declaration
+        /// order until one is present. Never empty: with no `from`/`alias` given it
+        /// holds exactly `[name]`; otherwise it holds the declared `from`/`alias`
+        /// values in order.
+        from: Vec<LitStr>,
+        /// `false` when `from` is a single name derived from the field ident (no
+        /// `from`/`alias` and no explicit `rename`, or a bare `from`), still eligible
+        /// for `rename_all`.
+        from_is_explicit: bool,
         default: Option<LitStr>,
+        default_with: Option<ExprPath>,
         with: Option<ExprPath>,
+        /// Combined validator expression built from `#[env(validate = ...)]`.
+        validate: Option<Expr>,
+        /// Set by `#[env(deprecated)]`/`#[env(deprecated = "...")]`; the generated code
+        /// warns when the var is present but still reads it normally.
+        deprecated: Option<Override<LitStr>>,
     },
     /// #[env(nested)]
-    Nested,
+    Nested {
+        /// `#[env(prefix = "...")]` prepended to the nested struct's own accumulated
+        /// prefix before looking up its flat fields.
+        prefix: Option<LitStr>,
+    },
     /// No config attr.
     None,
 }
@@ -45,9 +68,13 @@ impl FromField for FromEnvFieldReceiver {
         let option = parse_option(&ty).map(ToOwned::to_owned);
 
         let mut rename: Option<Override<LitStr>> = None;
-        let mut from: Option<Override<LitStr>> = None;
+        let mut from: Vec<Override<LitStr>> = Vec::new();
         let mut default: Option<LitStr> = None;
+        let mut default_with: Option<ExprPath> = None;
         let mut with: Option<ExprPath> = None;
+        let mut validate: Option<Expr> = None;
+        let mut deprecated: Option<Override<LitStr>> = None;
+        let mut prefix: Option<LitStr> = None;
         let mut nested = Flag::default();
         let mut ignored = Flag::default();
 
@@ -82,9 +109,9 @@ impl FromField for FromEnvFieldReceiver {
                         }
                     };
 
-                    if meta.path().is_ident("from") {
+                    if meta.path().is_ident("from") || meta.path().is_ident("alias") {
                         match FromMeta::from_meta(&meta) {
-                            Ok(v) => from = Some(v),
+                            Ok(v) => from.push(v),
                             Err(e) => {
                                 accumulator.push(e);
                             }
@@ -104,6 +131,14 @@ impl FromField for FromEnvFieldReceiver {
                                 accumulator.push(e);
                             }
                         }
+                    } else if meta.path().is_ident("default_with") {
+                        default_path_span = meta.path().span();
+                        match FromMeta::from_meta(&meta) {
+                            Ok(v) => default_with = Some(v),
+                            Err(e) => {
+                                accumulator.push(e);
+                            }
+                        }
                     } else if meta.path().is_ident("with") {
                         match FromMeta::from_meta(&meta) {
                             Ok(v) => with = Some(v),
@@ -111,6 +146,27 @@ impl FromField for FromEnvFieldReceiver {
                                 accumulator.push(e);
                             }
                         }
+                    } else if meta.path().is_ident("validate") {
+                        match parse_validator_attr(&meta) {
+                            Ok(v) => validate = Some(v),
+                            Err(e) => {
+                                accumulator.push(e);
+                            }
+                        }
+                    } else if meta.path().is_ident("deprecated") {
+                        match FromMeta::from_meta(&meta) {
+                            Ok(v) => deprecated = Some(v),
+                            Err(e) => {
+                                accumulator.push(e);
+                            }
+                        }
+                    } else if meta.path().is_ident("prefix") {
+                        match FromMeta::from_meta(&meta) {
+                            Ok(v) => prefix = Some(v),
+                            Err(e) => {
+                                accumulator.push(e);
+                            }
+                        }
                     } else if meta.path().is_ident("nested") {
                         match FromMeta::from_meta(&meta) {
                             Ok(v) => nested = v,
@@ -139,61 +195,110 @@ impl FromField for FromEnvFieldReceiver {
         const IGNORED_CLASH: &str = "`ignored` cannot be used with other attributes";
         const NESTED_CLASH: &str = "`nested` cannot be used with other attributes";
         const OPTION_WIH_DEFAULT: &str = "Optional fields cannot have a default";
+        const DEFAULT_CLASH: &str = "`default` and `default_with` cannot both be set";
+        const PREFIX_WITHOUT_NESTED: &str = "`prefix` can only be used together with `nested`";
+
+        if default.is_some() && default_with.is_some() {
+            accumulator.push(darling::Error::custom(DEFAULT_CLASH).with_span(&default_path_span));
+        }
+
+        let has_from = !from.is_empty();
 
         match (
-            from,
+            has_from,
             rename,
             default,
+            default_with,
             with,
+            validate,
+            deprecated,
+            prefix,
             nested.is_present(),
             ignored.is_present(),
         ) {
-            (Some(_), _, _, _, _, true)
-            | (_, Some(_), _, _, _, true)
-            | (_, _, Some(_), _, _, true)
-            | (_, _, _, Some(_), _, true)
-            | (_, _, _, _, true, true) => {
+            (true, _, _, _, _, _, _, _, _, true)
+            | (_, Some(_), _, _, _, _, _, _, _, true)
+            | (_, _, Some(_), _, _, _, _, _, _, true)
+            | (_, _, _, Some(_), _, _, _, _, _, true)
+            | (_, _, _, _, Some(_), _, _, _, _, true)
+            | (_, _, _, _, _, Some(_), _, _, _, true)
+            | (_, _, _, _, _, _, Some(_), _, _, true)
+            | (_, _, _, _, _, _, _, Some(_), _, true)
+            | (_, _, _, _, _, _, _, _, true, true) => {
                 accumulator.push(darling::Error::custom(IGNORED_CLASH).with_span(&ignored.span()));
 
                 Err(accumulator.finish().unwrap_err())
             }
-            (Some(_), _, _, _, true, _)
-            | (_, Some(_), _, _, true, _)
-            | (_, _, Some(_), _, true, _)
-            | (_, _, _, Some(_), true, _) => {
+            (true, _, _, _, _, _, _, _, true, _)
+            | (_, Some(_), _, _, _, _, _, _, true, _)
+            | (_, _, Some(_), _, _, _, _, _, true, _)
+            | (_, _, _, Some(_), _, _, _, _, true, _)
+            | (_, _, _, _, Some(_), _, _, _, true, _)
+            | (_, _, _, _, _, Some(_), _, _, true, _)
+            | (_, _, _, _, _, _, Some(_), _, true, _) => {
                 accumulator.push(darling::Error::custom(NESTED_CLASH).with_span(&nested.span()));
 
                 Err(accumulator.finish().unwrap_err())
             }
-            (None, None, None, None, false, true) => accumulator.finish_with(Self {
-                ident,
-                ty,
-                option,
-                doc_attrs,
-                env_attr: EnvAttribute::None,
-            }),
-            (None, None, None, None, true, false) => accumulator.finish_with(Self {
-                ident,
-                ty,
-                option,
-                doc_attrs,
-                env_attr: EnvAttribute::Nested,
-            }),
-            (from, rename, default, with, false, false) => {
-                if option.is_some() && default.is_some() {
+            (false, None, None, None, None, None, None, None, false, true) => {
+                accumulator.finish_with(Self {
+                    ident,
+                    ty,
+                    option,
+                    doc_attrs,
+                    env_attr: EnvAttribute::None,
+                })
+            }
+            (false, None, None, None, None, None, None, prefix, true, false) => {
+                accumulator.finish_with(Self {
+                    ident,
+                    ty,
+                    option,
+                    doc_attrs,
+                    env_attr: EnvAttribute::Nested { prefix },
+                })
+            }
+            (_, rename, default, default_with, with, validate, deprecated, prefix, false, false) => {
+                if option.is_some() && (default.is_some() || default_with.is_some()) {
                     let err =
                         darling::Error::custom(OPTION_WIH_DEFAULT).with_span(&default_path_span);
 
                     accumulator.push(err);
                 }
 
+                if let Some(prefix) = &prefix {
+                    let err =
+                        darling::Error::custom(PREFIX_WITHOUT_NESTED).with_span(&prefix.span());
+
+                    accumulator.push(err);
+                }
+
                 let default_name = || LitStr::new(&ident.to_string().to_uppercase(), ident.span());
+                let name_is_explicit = matches!(rename, Some(Override::Explicit(_)));
                 let name = match rename {
                     None => default_name(),
                     Some(rename) => rename.unwrap_or_else(default_name),
                 };
 
-                let from = from.map(|from| from.unwrap_or_else(default_name));
+                let from_is_explicit = match from.as_slice() {
+                    [] => name_is_explicit,
+                    [Override::Inherit] => false,
+                    _ => true,
+                };
+                // No `from`/`alias` at all still has to probe *something*, so fall
+                // back to the field's own lookup name rather than leaving the list
+                // empty.
+                let from = if from.is_empty() {
+                    vec![name.clone()]
+                } else {
+                    from.into_iter()
+                        .map(|from| from.unwrap_or_else(default_name))
+                        .collect::<Vec<_>>()
+                };
+                debug_assert!(
+                    !from.is_empty(),
+                    "`from` must always contain at least the field's lookup name"
+                );
 
                 accumulator.finish_with(Self {
                     ident,
@@ -202,9 +307,14 @@ impl FromField for FromEnvFieldReceiver {
                     doc_attrs,
                     env_attr: EnvAttribute::Flat {
                         name,
+                        name_is_explicit,
                         from,
+                        from_is_explicit,
                         default,
+                        default_with,
                         with,
+                        validate,
+                        deprecated,
                     },
                 })
             }
@@ -212,6 +322,212 @@ impl FromField for FromEnvFieldReceiver {
     }
 }
 
+/// Unwraps the outer `#[env(validate(...))]` or `#[env(validate = ...)]` meta and
+/// hands the inner validator meta to [`parse_validator`], which does the actual
+/// `and`/`or`/leaf dispatch.
+fn parse_validator_attr(meta: &Meta) -> darling::Result<Expr> {
+    let inner = match meta {
+        Meta::List(list) => {
+            let mut items = NestedMeta::parse_meta_list(list.tokens.clone())?.into_iter();
+
+            let first = items
+                .next()
+                .ok_or_else(|| darling::Error::too_few_items(1).with_span(&list.span()))?;
+
+            if items.next().is_some() {
+                return Err(
+                    darling::Error::custom("`validate` takes a single validator expression")
+                        .with_span(&list.span()),
+                );
+            }
+
+            match first {
+                NestedMeta::Meta(meta) => meta,
+                NestedMeta::Lit(lit) => return Err(darling::Error::unexpected_lit_type(&lit)),
+            }
+        }
+        // `validate = and(range(min = 1, max = 10), regex("^[A-Z]+$"))` parses the
+        // right-hand side as an `Expr::Call`; re-parse its tokens as a `Meta` so it
+        // goes through the same `and`/`or`/leaf dispatch as the parenthesized form.
+        Meta::NameValue(name_value) => syn::parse2(name_value.value.to_token_stream())?,
+        Meta::Path(_) => {
+            return Err(
+                darling::Error::custom("`validate` requires a validator expression")
+                    .with_span(&meta.span()),
+            );
+        }
+    };
+
+    parse_validator(&inner)
+}
+
+/// Recursively folds a validator nested-meta tree into a single
+/// [`Validator`](https://docs.rs/fromenv) expression, combining `and(..)`/`or(..)`
+/// groups with the matching [`Validator`] combinator and turning leaves into
+/// constructor calls (e.g. `regex("^[A-Z]+$")`); a leaf with named params such as
+/// `range(min = 1, max = 10)` becomes a builder-style method chain on its constructor.
+fn parse_validator(meta: &Meta) -> darling::Result<Expr> {
+    let path = meta.path();
+
+    if path.is_ident("and") || path.is_ident("or") {
+        let list = meta.require_list()?;
+        let nested_meta_list = NestedMeta::parse_meta_list(list.tokens.clone())?;
+
+        let mut items = Vec::with_capacity(nested_meta_list.len());
+        for nested in nested_meta_list {
+            match nested {
+                NestedMeta::Meta(meta) => items.push(parse_validator(&meta)?),
+                NestedMeta::Lit(lit) => {
+                    return Err(darling::Error::unexpected_lit_type(&lit));
+                }
+            }
+        }
+
+        let combine: fn(Expr, Expr) -> Expr = if path.is_ident("and") {
+            |acc, item| parse_quote!(::fromenv::Validator::and(#acc, #item))
+        } else {
+            |acc, item| parse_quote!(::fromenv::Validator::or(#acc, #item))
+        };
+
+        let mut items = items.into_iter();
+        let first = items
+            .next()
+            .ok_or_else(|| darling::Error::too_few_items(1).with_span(&list.span()))?;
+
+        return Ok(items.fold(first, combine));
+    }
+
+    let ident = path
+        .get_ident()
+        .ok_or_else(|| darling::Error::unknown_field_path(path).with_span(&meta.span()))?;
+
+    match meta {
+        Meta::List(list) => {
+            // Named params (`range(min = 1, max = 10)`) aren't valid as positional
+            // call args in Rust, so they become a builder-style method chain;
+            // anything else (e.g. `regex("^[A-Z]+$")`) is passed through positionally.
+            match NestedMeta::parse_meta_list(list.tokens.clone()) {
+                Ok(items) if !items.is_empty() && items.iter().all(is_name_value) => {
+                    let mut expr: Expr = parse_quote!(::fromenv::validator::#ident());
+                    for item in items {
+                        let NestedMeta::Meta(Meta::NameValue(name_value)) = item else {
+                            unreachable!("checked by is_name_value above")
+                        };
+                        let method = name_value
+                            .path
+                            .get_ident()
+                            .ok_or_else(|| darling::Error::unsupported_shape("expected a param name"))?;
+                        let value = &name_value.value;
+                        expr = parse_quote!(#expr.#method(#value));
+                    }
+                    Ok(expr)
+                }
+                _ => {
+                    let tokens = &list.tokens;
+                    Ok(parse_quote!(::fromenv::validator::#ident(#tokens)))
+                }
+            }
+        }
+        Meta::NameValue(name_value) => {
+            let value = &name_value.value;
+            Ok(parse_quote!(::fromenv::validator::#ident(#value)))
+        }
+        Meta::Path(_) => Ok(parse_quote!(::fromenv::validator::#ident())),
+    }
+}
+
+fn is_name_value(nested: &NestedMeta) -> bool {
+    matches!(nested, NestedMeta::Meta(Meta::NameValue(_)))
+}
+
+impl FromEnvFieldReceiver {
+    /// Applies a container-level `#[env(rename_all = "...")]` rule to this field's
+    /// derived env var name(s), leaving any explicit `rename`/`from` untouched.
+    pub fn apply_rename_rule(&mut self, rule: RenameRule) {
+        let EnvAttribute::Flat {
+            name,
+            name_is_explicit,
+            from,
+            from_is_explicit,
+            ..
+        } = &mut self.env_attr
+        else {
+            return;
+        };
+
+        if !*name_is_explicit {
+            *name = LitStr::new(&rule.apply(&self.ident.to_string()), name.span());
+        }
+
+        if !*from_is_explicit {
+            if let [from] = from.as_mut_slice() {
+                *from = LitStr::new(&rule.apply(&self.ident.to_string()), from.span());
+            }
+        }
+    }
+}
+
+/// Container-level field-name case convention, mirroring serde's `rename_all`.
+///
+/// Fields are assumed to start out `snake_case`; each variant splits on `_` and
+/// re-joins the words according to its own convention.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RenameRule {
+    Lowercase,
+    Uppercase,
+    Pascal,
+    Camel,
+    Snake,
+    #[default]
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    pub fn apply(&self, field: &str) -> String {
+        match self {
+            RenameRule::Lowercase | RenameRule::Snake => field.to_owned(),
+            RenameRule::Uppercase | RenameRule::ScreamingSnake => field.to_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                for word in field.split('_') {
+                    let mut chars = word.chars();
+                    pascal.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+                    pascal.push_str(chars.as_str());
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake.apply(field).replace('_', "-"),
+        }
+    }
+}
+
+impl FromMeta for RenameRule {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "lowercase" => Ok(RenameRule::Lowercase),
+            "UPPERCASE" => Ok(RenameRule::Uppercase),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "camelCase" => Ok(RenameRule::Camel),
+            "snake_case" => Ok(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            "kebab-case" => Ok(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(RenameRule::ScreamingKebab),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
 fn parse_option(ty: &Type) -> Option<&Type> {
     let Type::Path(type_path) = ty else {
         return None;